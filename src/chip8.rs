@@ -1,9 +1,39 @@
 use crate::memory;
 use crate::display;
+use crate::instruction::{self, Instruction};
 use rand::Rng;
+use std::fs;
+use std::io;
+use std::path::Path;
 
 const STACK_SIZE: usize = 16;
 
+// Quirks toggles the handful of incompatible behaviors that differ between the
+// original COSMAC VIP interpreter and the later SCHIP derivatives, so that one
+// core can run both dialects of ROM instead of silently mis-executing them.
+pub struct Quirks {
+    // op_8xy6/op_8xye copy Vy into Vx before shifting instead of shifting Vx in place
+    pub shift_uses_vy: bool,
+    // op_bnnn jumps to nnn + V[x] rather than nnn + V0
+    pub jump_uses_vx: bool,
+    // Fx55/Fx65 leave I pointing past the last register they touched
+    pub load_store_increments_i: bool,
+    // the logic opcodes (AND/OR/XOR) reset VF to 0
+    pub reset_vf_on_logic: bool,
+}
+
+// the defaults follow the original COSMAC VIP behavior
+impl Default for Quirks {
+    fn default() -> Self {
+        Quirks {
+            shift_uses_vy: true,
+            jump_uses_vx: false,
+            load_store_increments_i: true,
+            reset_vf_on_logic: true,
+        }
+    }
+}
+
 // CHIP8 is an interpreter to execute instructions
 pub struct CHIP8 {
     // the program counter
@@ -12,7 +42,7 @@ pub struct CHIP8 {
     // http://devernay.free.fr/hacks/chip8/C8TECH10.HTM#2.2
     sp: u8,
     // memory index register
-    ix: u8,
+    ix: u16,
     // the input keypad
     keypad: [u8; 16],
     // the stack holds 16, 16 bit values
@@ -27,16 +57,23 @@ pub struct CHIP8 {
     // delay and sound timers that are decremented at the rate of 60Hz when > 0
     delay_timer: u8,
     sound_timer: u8,
+    // quirks selects between the COSMAC-VIP and SCHIP-style behaviors
+    quirks: Quirks,
 }
 
 // Yet another CHIP-8 emulator
 impl CHIP8 {
-    // new creates a new CHIP8 instance
+    // new creates a new CHIP8 instance with the default (COSMAC VIP) quirks
     pub fn new() -> Self {
+        CHIP8::with_quirks(Quirks::default())
+    }
+
+    // with_quirks creates a new CHIP8 instance running the given quirks
+    pub fn with_quirks(quirks: Quirks) -> Self {
         let chip8 = CHIP8 {
-            pc: 0u16,
+            pc: 0x200u16,
             sp: 0u8,
-            ix: 0u8,
+            ix: 0u16,
             keypad: [0u8; 16],
             stack: [0u16; 16],
             vx: [0; 16],
@@ -44,6 +81,7 @@ impl CHIP8 {
             display: display::Display::new(),
             delay_timer: 0u8,
             sound_timer: 0u8,
+            quirks,
         };
 
         chip8
@@ -66,6 +104,60 @@ impl CHIP8 {
         }
     }
 
+    // load_rom reads a CHIP8 program from a file on disk into memory at 0x200
+    pub fn load_rom<P: AsRef<Path>>(&mut self, path: P) -> Result<(), io::Error> {
+        let data = fs::read(path)?;
+        self.load(&data);
+        Ok(())
+    }
+
+    // step performs a single fetch/decode/execute cycle
+    pub fn step(&mut self) {
+        let opcode = self.fetch_opcode();
+        self.execute_opcode(opcode);
+    }
+
+    // run executes `cycles_per_frame` instructions and then ticks the timers
+    // once, so a caller can target a chosen CPU clock at a fixed 60Hz frame rate
+    pub fn run(&mut self, cycles_per_frame: usize) {
+        for _ in 0..cycles_per_frame {
+            self.step();
+        }
+        self.tick_timers();
+    }
+
+    // set_key records whether a key (0x0 to 0xF) is currently pressed so the
+    // Ex9E/ExA1/Fx0A opcodes can read the input state
+    pub fn set_key(&mut self, key: u8, pressed: bool) {
+        self.keypad[key as usize] = if pressed { 1 } else { 0 };
+    }
+
+    // tick_timers decrements the delay and sound timers toward zero. It is meant
+    // to be driven on its own 60Hz clock, independently of instruction execution.
+    pub fn tick_timers(&mut self) {
+        if self.delay_timer > 0 {
+            self.delay_timer -= 1;
+        }
+        if self.sound_timer > 0 {
+            self.sound_timer -= 1;
+        }
+    }
+
+    // is_beeping reports whether the sound timer is active so a frontend can
+    // drive audio output
+    pub fn is_beeping(&self) -> bool {
+        self.sound_timer > 0
+    }
+
+    // write_mem writes a byte into memory, panicking on an out of range address
+    // like the rest of the interpreter does
+    fn write_mem(&mut self, address: u16, val: u8) {
+        match self.memory.write(address, val) {
+            Ok(_) => (),
+            Err(_) => panic!("failed to write contents to memory"),
+        };
+    }
+
     // get's the opcode using the program counter
     fn fetch_opcode(&mut self) -> u16 {
         // First we fetch the opcode from memory
@@ -85,68 +177,66 @@ impl CHIP8 {
     }
 
     fn execute_opcode(&mut self, opcode: u16) {
-        let ins = opcode & 0xF000;
-        let nnn = (opcode & 0x0FFF) as u8;
-        let nn = (opcode & 0x0FF0) as u8;
-        let kk = (opcode & 0x00FF) as u8;
-        let x = (opcode & 0x0F00) as u8;
-        let y = (opcode & 0x00F0) as u8;
-        let n = (opcode & 0x000F) as u8;
-        // first we determine the top level instruction
-        match ins {
-            0x000 => {
-                // disambiguate the opcode by comparing the last 4 bits
-                match opcode & 0x000F {
-                    0x0000 => self.op_00e0(), // Execute 00E0,
-                    0x000E => self.op_00ee(), // Execute 00EE,
-                    _ => (),
-                }
-            }
-            0x100 => self.op_1nnn(nnn),
-            0x200 => self.op_2nnn(nnn),
-            0x300 => self.op_3xkk( x, kk),
-            0x400 => self.op_4xkk( x, kk),
-            0x500 => self.op_5xy0(x, y),
-            0x600 => self.op_6xkk(x, kk),
-            0x700 => self.op_7xkk(x, kk),
-            0x800 => {
-                match opcode & 0x000F {
-                    0x800 => self.op_8xy0(x, y),
-                    0x801 => self.op_8xy1(x, y),
-                    0x802 => self.op_8xy2(x, y),
-                    0x803 => self.op_8xy3(x, y),
-                    0x804 => self.op_8xy4(x, y),
-                    0x805 => self.op_8xy5(x, y),
-                    0x806 => self.op_8xy6(x),
-                    0x807 => self.op_8xy7(x, y),
-                    0x80E => self.op_8xye(x),
-                    _=> (),
-                }
-            }
-            0x900 => self.op_9xy0(x, y),
-            0xa00 => self.op_annn(nnn),
-            0xb00 => self.op_bnnn(nnn),
-            0xc00 => self.op_cxkk(x, kk),
-            _ => (),
+        // decode once, then dispatch; the disassembler shares this decoder
+        match instruction::decode(opcode) {
+            Instruction::Cls => self.op_00e0(),
+            Instruction::Ret => self.op_00ee(),
+            Instruction::Jp(nnn) => self.op_1nnn(nnn),
+            Instruction::Call(nnn) => self.op_2nnn(nnn),
+            Instruction::SeVxByte(x, kk) => self.op_3xkk(x, kk),
+            Instruction::SneVxByte(x, kk) => self.op_4xkk(x, kk),
+            Instruction::SeVxVy(x, y) => self.op_5xy0(x, y),
+            Instruction::LdVxByte(x, kk) => self.op_6xkk(x, kk),
+            Instruction::AddVxByte(x, kk) => self.op_7xkk(x, kk),
+            Instruction::LdVxVy(x, y) => self.op_8xy0(x, y),
+            Instruction::OrVxVy(x, y) => self.op_8xy1(x, y),
+            Instruction::AndVxVy(x, y) => self.op_8xy2(x, y),
+            Instruction::XorVxVy(x, y) => self.op_8xy3(x, y),
+            Instruction::AddVxVy(x, y) => self.op_8xy4(x, y),
+            Instruction::SubVxVy(x, y) => self.op_8xy5(x, y),
+            Instruction::ShrVxVy(x, y) => self.op_8xy6(x, y),
+            Instruction::SubnVxVy(x, y) => self.op_8xy7(x, y),
+            Instruction::ShlVxVy(x, y) => self.op_8xye(x, y),
+            Instruction::SneVxVy(x, y) => self.op_9xy0(x, y),
+            Instruction::LdI(nnn) => self.op_annn(nnn),
+            Instruction::JpV0(nnn, x) => self.op_bnnn(nnn, x),
+            Instruction::Rnd(x, kk) => self.op_cxkk(x, kk),
+            Instruction::Drw(x, y, n) => self.op_dxyn(x, y, n),
+            Instruction::Skp(x) => self.op_ex9e(x),
+            Instruction::Sknp(x) => self.op_exa1(x),
+            Instruction::LdVxDt(x) => self.op_fx07(x),
+            Instruction::LdVxK(x) => self.op_fx0a(x),
+            Instruction::LdDtVx(x) => self.op_fx15(x),
+            Instruction::LdStVx(x) => self.op_fx18(x),
+            Instruction::AddIVx(x) => self.op_fx1e(x),
+            Instruction::LdFVx(x) => self.op_fx29(x),
+            Instruction::LdBVx(x) => self.op_fx33(x),
+            Instruction::LdIVx(x) => self.op_fx55(x),
+            Instruction::LdVxI(x) => self.op_fx65(x),
+            Instruction::Unknown(_) => (),
         }
     }
 
     // clear display
-    fn op_00e0(&mut self) {}
+    fn op_00e0(&mut self) {
+        self.display.clear();
+        self.pc = self.pc + 2;
+    }
 
     // return from a subroutine
     fn op_00ee(&mut self) {
+        // read the pushed frame first, then pop it off the stack
+        self.pc = self.stack[self.sp as usize];
         self.sp -= 1;
-        self.pc = self.stack[self.sp as usize]
     }
 
     // jump to address
-    fn op_1nnn(&mut self, nnn: u8) {
-        self.pc = nnn as u16
+    fn op_1nnn(&mut self, nnn: u16) {
+        self.pc = nnn
     }
 
     // call address
-    fn op_2nnn(&mut self, nnn: u8) {
+    fn op_2nnn(&mut self, nnn: u16) {
         // first we increment the stack pointer
         self.sp = self.sp + 1;
         // then we put the current program counter on top of the stack
@@ -154,13 +244,15 @@ impl CHIP8 {
         // the program counter
         self.stack[self.sp as usize] = self.pc + 2;
         // then we finally set the program counter to nnn
-        self.pc = nnn as u16;
+        self.pc = nnn;
     }
 
     // skip instruction if Vx == kk and instruction is 3xkk
     fn op_3xkk(&mut self, x: u8, kk: u8) {
         if self.vx[x as usize] == kk {
             self.pc = self.pc + 4;
+        } else {
+            self.pc = self.pc + 2;
         }
     }
 
@@ -168,6 +260,8 @@ impl CHIP8 {
     fn op_4xkk(&mut self, x: u8, kk: u8) {
         if self.vx[x as usize] != kk {
             self.pc = self.pc + 4;
+        } else {
+            self.pc = self.pc + 2;
         }
     }
 
@@ -175,6 +269,8 @@ impl CHIP8 {
     fn op_5xy0(&mut self, x: u8, y: u8) {
         if self.vx[x as usize] == self.vx[y as usize] {
             self.pc = self.pc + 4;
+        } else {
+            self.pc = self.pc + 2;
         }
     }
 
@@ -186,7 +282,7 @@ impl CHIP8 {
 
     // adds the value of kk into Vx and stores the result in Vx
     fn op_7xkk(&mut self, x: u8, kk: u8) {
-        self.vx[x as usize] = self.vx[x as usize] | kk;
+        self.vx[x as usize] = self.vx[x as usize].wrapping_add(kk);
         self.pc = self.pc + 2;
     }
 
@@ -199,18 +295,27 @@ impl CHIP8 {
     // perform a bitwise OR on the values of Vx and Vy, then stores the result in Vx
     fn op_8xy1(&mut self, x: u8, y: u8) {
         self.vx[x as usize] |= self.vx[y as usize];
+        if self.quirks.reset_vf_on_logic {
+            self.vx[0x0f] = 0;
+        }
         self.pc = self.pc + 2;
     }
 
-    // perform a bitwise OR on the values of Vx and Vy, then stores the result in Vx
+    // perform a bitwise AND on the values of Vx and Vy, then stores the result in Vx
     fn op_8xy2(&mut self, x: u8, y: u8) {
         self.vx[x as usize] &= self.vx[y as usize];
+        if self.quirks.reset_vf_on_logic {
+            self.vx[0x0f] = 0;
+        }
         self.pc = self.pc + 2;
     }
 
-    // perform a bitwise OR on the values of Vx and Vy, then stores the result in Vx
+    // perform a bitwise XOR on the values of Vx and Vy, then stores the result in Vx
     fn op_8xy3(&mut self, x: u8, y: u8) {
         self.vx[x as usize] ^= self.vx[y as usize];
+        if self.quirks.reset_vf_on_logic {
+            self.vx[0x0f] = 0;
+        }
         self.pc = self.pc + 2;
     }
 
@@ -231,10 +336,15 @@ impl CHIP8 {
         self.pc = self.pc + 2;
     }
 
-    // set VF to 1 if LSB of Vx is 1, otherwise 0, then divide Vx by 2
-    fn op_8xy6(&mut self, x: u8) {
-        self.vx[0x0f] = self.vx[x as usize] & 0b10000000;
+    // shift Vx right by one, storing the shifted out LSB in VF. With the shift
+    // quirk set, Vy is copied into Vx before the shift.
+    fn op_8xy6(&mut self, x: u8, y: u8) {
+        if self.quirks.shift_uses_vy {
+            self.vx[x as usize] = self.vx[y as usize];
+        }
+        let lsb = self.vx[x as usize] & 0b00000001;
         self.vx[x as usize] >>= 1;
+        self.vx[0x0f] = lsb;
         self.pc = self.pc + 2;
     }
 
@@ -246,32 +356,157 @@ impl CHIP8 {
         self.pc = self.pc + 2;
     }
 
-     // set VF to 1 if LSB of Vx is 1, otherwise 0, then multiply Vx by 2
-    fn op_8xye(&mut self, x: u8) {
-        self.vx[0x0f] = self.vx[x as usize] & 0b10000000;
-        self.vx[x as usize] >>= 1;
+     // shift Vx left by one, storing the shifted out MSB in VF. With the shift
+     // quirk set, Vy is copied into Vx before the shift.
+    fn op_8xye(&mut self, x: u8, y: u8) {
+        if self.quirks.shift_uses_vy {
+            self.vx[x as usize] = self.vx[y as usize];
+        }
+        let msb = (self.vx[x as usize] & 0b10000000) >> 7;
+        self.vx[x as usize] <<= 1;
+        self.vx[0x0f] = msb;
         self.pc = self.pc + 2;
     }
 
     // skip next instruction if Vx != Vy.
     fn op_9xy0(&mut self, x: u8, y:u8) {
-        self.pc = if self.vx[x as usize] != self.vx[y as usize] {self.pc + 4} else {return};
+        self.pc = if self.vx[x as usize] != self.vx[y as usize] {self.pc + 4} else {self.pc + 2};
     }
 
-    // sets the value of the I register to nnn.
-    fn op_annn(&mut self, nnn: u8) {
+    // sets the value of the I register to the full 12-bit nnn.
+    fn op_annn(&mut self, nnn: u16) {
         self.ix = nnn;
+        self.pc = self.pc + 2;
     }
 
-    // jumps to the location of nnn + v0
-    fn op_bnnn(&mut self, nnn: u8) {
-        self.pc = (nnn + self.vx[0]).into();
+    // jumps to the location of nnn + V0, or nnn + V[x] when the jump quirk is set
+    fn op_bnnn(&mut self, nnn: u16, x: u8) {
+        let offset = if self.quirks.jump_uses_vx {
+            self.vx[x as usize]
+        } else {
+            self.vx[0]
+        };
+        self.pc = nnn + offset as u16;
     }
 
     // sets vx to random byte AND kk
     fn op_cxkk(&mut self, x: u8, kk : u8) {
         let mut rng = rand::thread_rng();
         self.vx[x as usize] = rng.gen::<u8>() & kk;
+        self.pc = self.pc + 2;
+    }
+
+    // draws an n-byte sprite stored starting at the I register to the screen at
+    // (Vx, Vy), setting VF to 1 when the draw collides with an existing pixel
+    fn op_dxyn(&mut self, x: u8, y: u8, n: u8) {
+        let mut rows = [0u8; 16];
+        for i in 0..n as usize {
+            rows[i] = match self.memory.read(self.ix + i as u16) {
+                Ok(byte) => byte,
+                Err(_) => panic!("failed to read sprite from memory"),
+            };
+        }
+        let collision = self
+            .display
+            .draw_sprite(self.vx[x as usize], self.vx[y as usize], &rows[..n as usize]);
+        self.vx[0x0f] = if collision { 1 } else { 0 };
+        self.pc = self.pc + 2;
+    }
+
+    // skip the next instruction if the key held in Vx is currently pressed
+    fn op_ex9e(&mut self, x: u8) {
+        if self.keypad[self.vx[x as usize] as usize] != 0 {
+            self.pc = self.pc + 4;
+        } else {
+            self.pc = self.pc + 2;
+        }
+    }
+
+    // skip the next instruction if the key held in Vx is not pressed
+    fn op_exa1(&mut self, x: u8) {
+        if self.keypad[self.vx[x as usize] as usize] == 0 {
+            self.pc = self.pc + 4;
+        } else {
+            self.pc = self.pc + 2;
+        }
+    }
+
+    // set Vx to the current value of the delay timer
+    fn op_fx07(&mut self, x: u8) {
+        self.vx[x as usize] = self.delay_timer;
+        self.pc = self.pc + 2;
+    }
+
+    // set the delay timer to Vx
+    fn op_fx15(&mut self, x: u8) {
+        self.delay_timer = self.vx[x as usize];
+        self.pc = self.pc + 2;
+    }
+
+    // set the sound timer to Vx
+    fn op_fx18(&mut self, x: u8) {
+        self.sound_timer = self.vx[x as usize];
+        self.pc = self.pc + 2;
+    }
+
+    // add Vx to the I register
+    fn op_fx1e(&mut self, x: u8) {
+        self.ix = self.ix + self.vx[x as usize] as u16;
+        self.pc = self.pc + 2;
+    }
+
+    // point I at the built-in hex font sprite for the digit in Vx. The font is
+    // stored at the start of memory and each sprite is 5 bytes tall.
+    fn op_fx29(&mut self, x: u8) {
+        self.ix = self.vx[x as usize] as u16 * 5;
+        self.pc = self.pc + 2;
+    }
+
+    // store the binary-coded decimal of Vx as three bytes starting at I:
+    // hundreds, tens, then ones
+    fn op_fx33(&mut self, x: u8) {
+        let val = self.vx[x as usize];
+        self.write_mem(self.ix, val / 100);
+        self.write_mem(self.ix + 1, (val / 10) % 10);
+        self.write_mem(self.ix + 2, val % 10);
+        self.pc = self.pc + 2;
+    }
+
+    // dump registers V0 through Vx into memory starting at I
+    fn op_fx55(&mut self, x: u8) {
+        for i in 0..=x as usize {
+            self.write_mem(self.ix + i as u16, self.vx[i]);
+        }
+        if self.quirks.load_store_increments_i {
+            self.ix = self.ix + x as u16 + 1;
+        }
+        self.pc = self.pc + 2;
+    }
+
+    // load registers V0 through Vx from memory starting at I
+    fn op_fx65(&mut self, x: u8) {
+        for i in 0..=x as usize {
+            self.vx[i] = match self.memory.read(self.ix + i as u16) {
+                Ok(byte) => byte,
+                Err(_) => panic!("failed to read contents from memory"),
+            };
+        }
+        if self.quirks.load_store_increments_i {
+            self.ix = self.ix + x as u16 + 1;
+        }
+        self.pc = self.pc + 2;
+    }
+
+    // block until any key is pressed, then store its index in Vx. pc is left
+    // untouched while waiting so the same opcode re-executes each cycle.
+    fn op_fx0a(&mut self, x: u8) {
+        for (key, &state) in self.keypad.iter().enumerate() {
+            if state != 0 {
+                self.vx[x as usize] = key as u8;
+                self.pc = self.pc + 2;
+                return;
+            }
+        }
     }
 }
 
@@ -291,4 +526,112 @@ mod tests {
             assert_eq!(want, got);
         }
     }
+
+    #[test]
+    fn test_step_runs_program() {
+        let mut chip = CHIP8::new();
+        // LD I, 0x300 ; LD V0, 0x05 ; ADD V0, 0x07 (OR would give 0x07, ADD gives 0x0C)
+        let program: [u8; 6] = [0xA3, 0x00, 0x60, 0x05, 0x70, 0x07];
+        chip.load(&program);
+        chip.step();
+        assert_eq!(chip.ix, 0x300);
+        chip.step();
+        assert_eq!(chip.vx[0], 0x05);
+        chip.step();
+        assert_eq!(chip.vx[0], 0x0C);
+        // three two-byte instructions executed from 0x200
+        assert_eq!(chip.pc, 0x206);
+    }
+
+    #[test]
+    fn test_dxyn_collision_clears_pixels() {
+        let mut chip = CHIP8::new();
+        // a single fully lit sprite row pointed at by I
+        chip.memory.write(0x300, 0xFF).unwrap();
+        chip.ix = 0x300;
+        chip.vx[0] = 0;
+        chip.vx[1] = 0;
+        // the first draw lights eight pixels without a collision
+        chip.execute_opcode(0xD011);
+        assert_eq!(chip.vx[0x0f], 0);
+        for px in 0..8 {
+            assert_eq!(chip.display.pixels()[px][0], 1);
+        }
+        // redrawing the same sprite XORs it back off and flags the collision
+        chip.execute_opcode(0xD011);
+        assert_eq!(chip.vx[0x0f], 1);
+        for px in 0..8 {
+            assert_eq!(chip.display.pixels()[px][0], 0);
+        }
+    }
+
+    // the SCHIP dialect flips every quirk away from the COSMAC VIP defaults
+    fn schip() -> CHIP8 {
+        CHIP8::with_quirks(Quirks {
+            shift_uses_vy: false,
+            jump_uses_vx: true,
+            load_store_increments_i: false,
+            reset_vf_on_logic: false,
+        })
+    }
+
+    #[test]
+    fn test_shift_uses_vy_quirk() {
+        // VIP copies Vy into Vx before shifting; SCHIP shifts Vx in place
+        let mut vip = CHIP8::new();
+        vip.vx[0] = 1;
+        vip.vx[1] = 4;
+        vip.execute_opcode(0x8016);
+        assert_eq!(vip.vx[0], 2);
+
+        let mut schip = schip();
+        schip.vx[0] = 1;
+        schip.vx[1] = 4;
+        schip.execute_opcode(0x8016);
+        assert_eq!(schip.vx[0], 0);
+    }
+
+    #[test]
+    fn test_jump_uses_vx_quirk() {
+        // Bnnn adds V0 on the VIP but V[x] on SCHIP
+        let mut vip = CHIP8::new();
+        vip.vx[0] = 1;
+        vip.vx[2] = 3;
+        vip.execute_opcode(0xB204);
+        assert_eq!(vip.pc, 0x205);
+
+        let mut schip = schip();
+        schip.vx[0] = 1;
+        schip.vx[2] = 3;
+        schip.execute_opcode(0xB204);
+        assert_eq!(schip.pc, 0x207);
+    }
+
+    #[test]
+    fn test_load_store_increments_i_quirk() {
+        // Fx65 leaves I advanced past the loaded registers on the VIP
+        let mut vip = CHIP8::new();
+        vip.ix = 0x300;
+        vip.execute_opcode(0xF265);
+        assert_eq!(vip.ix, 0x303);
+
+        let mut schip = schip();
+        schip.ix = 0x300;
+        schip.execute_opcode(0xF265);
+        assert_eq!(schip.ix, 0x300);
+    }
+
+    #[test]
+    fn test_reset_vf_on_logic_quirk() {
+        // the logic opcodes clear VF on the VIP but leave it untouched on SCHIP
+        let mut vip = CHIP8::new();
+        vip.vx[0x0f] = 1;
+        vip.execute_opcode(0x8011);
+        assert_eq!(vip.vx[0x0f], 0);
+
+        let mut schip = schip();
+        schip.vx[0x0f] = 1;
+        schip.execute_opcode(0x8011);
+        assert_eq!(schip.vx[0x0f], 1);
+    }
 }
@@ -0,0 +1,65 @@
+use crate::instruction::{decode, Instruction};
+
+// PROGRAM_START is where CHIP-8 programs are loaded into memory
+const PROGRAM_START: u16 = 0x200;
+
+// disassemble walks a program two bytes at a time, decoding each opcode into a
+// human-readable assembly line. Addresses are numbered as if the program were
+// loaded at 0x200. Each entry is (address, opcode, mnemonic); opcodes that do
+// not decode are emitted as a `DB 0xNNNN` data byte form.
+pub fn disassemble(program: &[u8]) -> Vec<(u16, u16, String)> {
+    let mut lines = Vec::new();
+    let mut addr = PROGRAM_START;
+    for pair in program.chunks(2) {
+        // a trailing odd byte is treated as the high byte of an opcode
+        let opcode = match pair {
+            [hi, lo] => (*hi as u16) << 8 | *lo as u16,
+            [hi] => (*hi as u16) << 8,
+            _ => 0,
+        };
+        lines.push((addr, opcode, mnemonic(opcode)));
+        addr = addr.wrapping_add(2);
+    }
+    lines
+}
+
+// mnemonic renders a single opcode as a line of CHIP-8 assembly
+fn mnemonic(opcode: u16) -> String {
+    match decode(opcode) {
+        Instruction::Cls => "CLS".to_string(),
+        Instruction::Ret => "RET".to_string(),
+        Instruction::Jp(nnn) => format!("JP 0x{:03X}", nnn),
+        Instruction::Call(nnn) => format!("CALL 0x{:03X}", nnn),
+        Instruction::SeVxByte(x, kk) => format!("SE V{}, 0x{:02X}", x, kk),
+        Instruction::SneVxByte(x, kk) => format!("SNE V{}, 0x{:02X}", x, kk),
+        Instruction::SeVxVy(x, y) => format!("SE V{}, V{}", x, y),
+        Instruction::LdVxByte(x, kk) => format!("LD V{}, 0x{:02X}", x, kk),
+        Instruction::AddVxByte(x, kk) => format!("ADD V{}, 0x{:02X}", x, kk),
+        Instruction::LdVxVy(x, y) => format!("LD V{}, V{}", x, y),
+        Instruction::OrVxVy(x, y) => format!("OR V{}, V{}", x, y),
+        Instruction::AndVxVy(x, y) => format!("AND V{}, V{}", x, y),
+        Instruction::XorVxVy(x, y) => format!("XOR V{}, V{}", x, y),
+        Instruction::AddVxVy(x, y) => format!("ADD V{}, V{}", x, y),
+        Instruction::SubVxVy(x, y) => format!("SUB V{}, V{}", x, y),
+        Instruction::ShrVxVy(x, y) => format!("SHR V{}, V{}", x, y),
+        Instruction::SubnVxVy(x, y) => format!("SUBN V{}, V{}", x, y),
+        Instruction::ShlVxVy(x, y) => format!("SHL V{}, V{}", x, y),
+        Instruction::SneVxVy(x, y) => format!("SNE V{}, V{}", x, y),
+        Instruction::LdI(nnn) => format!("LD I, 0x{:03X}", nnn),
+        Instruction::JpV0(nnn, _) => format!("JP V0, 0x{:03X}", nnn),
+        Instruction::Rnd(x, kk) => format!("RND V{}, 0x{:02X}", x, kk),
+        Instruction::Drw(x, y, n) => format!("DRW V{}, V{}, {}", x, y, n),
+        Instruction::Skp(x) => format!("SKP V{}", x),
+        Instruction::Sknp(x) => format!("SKNP V{}", x),
+        Instruction::LdVxDt(x) => format!("LD V{}, DT", x),
+        Instruction::LdVxK(x) => format!("LD V{}, K", x),
+        Instruction::LdDtVx(x) => format!("LD DT, V{}", x),
+        Instruction::LdStVx(x) => format!("LD ST, V{}", x),
+        Instruction::AddIVx(x) => format!("ADD I, V{}", x),
+        Instruction::LdFVx(x) => format!("LD F, V{}", x),
+        Instruction::LdBVx(x) => format!("LD B, V{}", x),
+        Instruction::LdIVx(x) => format!("LD [I], V{}", x),
+        Instruction::LdVxI(x) => format!("LD V{}, [I]", x),
+        Instruction::Unknown(oc) => format!("DB 0x{:04X}", oc),
+    }
+}
@@ -17,4 +17,38 @@ impl Display {
 
         display
     }
+
+    // clear turns every pixel off
+    pub fn clear(&mut self) {
+        self.screen = [[0; CHIP8_HEIGHT]; CHIP8_WIDTH];
+    }
+
+    // draw_sprite XORs an 8-pixel wide, `rows.len()` tall sprite onto the screen
+    // at (x, y). Each sprite row is drawn most significant bit first and the
+    // coordinates wrap around the edges of the display. It returns true when the
+    // XOR turns an already lit pixel off, which the caller uses as the collision
+    // flag.
+    pub fn draw_sprite(&mut self, x: u8, y: u8, rows: &[u8]) -> bool {
+        let mut collision = false;
+        for (dy, &byte) in rows.iter().enumerate() {
+            for dx in 0..8 {
+                if (byte >> (7 - dx)) & 1 == 0 {
+                    continue;
+                }
+                let px = (x as usize + dx) % CHIP8_WIDTH;
+                let py = (y as usize + dy) % CHIP8_HEIGHT;
+                if self.screen[px][py] == 1 {
+                    collision = true;
+                }
+                self.screen[px][py] ^= 1;
+            }
+        }
+        collision
+    }
+
+    // pixels hands out a read-only view of the framebuffer so a frontend can
+    // render it
+    pub fn pixels(&self) -> &[[u8; CHIP8_HEIGHT]; CHIP8_WIDTH] {
+        &self.screen
+    }
 }
\ No newline at end of file
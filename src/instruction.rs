@@ -0,0 +1,99 @@
+// Instruction is the decoded form of a CHIP-8 opcode. Both the interpreter and
+// the disassembler decode through the same nibble logic so there is a single
+// source of truth for what each opcode means.
+pub enum Instruction {
+    Cls,                  // 00E0
+    Ret,                  // 00EE
+    Jp(u16),              // 1nnn
+    Call(u16),            // 2nnn
+    SeVxByte(u8, u8),     // 3xkk
+    SneVxByte(u8, u8),    // 4xkk
+    SeVxVy(u8, u8),       // 5xy0
+    LdVxByte(u8, u8),     // 6xkk
+    AddVxByte(u8, u8),    // 7xkk
+    LdVxVy(u8, u8),       // 8xy0
+    OrVxVy(u8, u8),       // 8xy1
+    AndVxVy(u8, u8),      // 8xy2
+    XorVxVy(u8, u8),      // 8xy3
+    AddVxVy(u8, u8),      // 8xy4
+    SubVxVy(u8, u8),      // 8xy5
+    ShrVxVy(u8, u8),      // 8xy6
+    SubnVxVy(u8, u8),     // 8xy7
+    ShlVxVy(u8, u8),      // 8xyE
+    SneVxVy(u8, u8),      // 9xy0
+    LdI(u16),             // Annn
+    JpV0(u16, u8),        // Bnnn (x is carried for the jump quirk)
+    Rnd(u8, u8),          // Cxkk
+    Drw(u8, u8, u8),      // Dxyn
+    Skp(u8),              // Ex9E
+    Sknp(u8),             // ExA1
+    LdVxDt(u8),           // Fx07
+    LdVxK(u8),            // Fx0A
+    LdDtVx(u8),           // Fx15
+    LdStVx(u8),           // Fx18
+    AddIVx(u8),           // Fx1E
+    LdFVx(u8),            // Fx29
+    LdBVx(u8),            // Fx33
+    LdIVx(u8),            // Fx55
+    LdVxI(u8),            // Fx65
+    Unknown(u16),
+}
+
+// decode turns a raw 16-bit opcode into its Instruction variant by pulling the
+// same nnn/kk/x/y/n nibbles the interpreter dispatches on.
+pub fn decode(opcode: u16) -> Instruction {
+    let nnn = opcode & 0x0FFF;
+    let kk = (opcode & 0x00FF) as u8;
+    let x = ((opcode & 0x0F00) >> 8) as u8;
+    let y = ((opcode & 0x00F0) >> 4) as u8;
+    let n = (opcode & 0x000F) as u8;
+    match opcode & 0xF000 {
+        0x0000 => match opcode & 0x00FF {
+            0xE0 => Instruction::Cls,
+            0xEE => Instruction::Ret,
+            _ => Instruction::Unknown(opcode),
+        },
+        0x1000 => Instruction::Jp(nnn),
+        0x2000 => Instruction::Call(nnn),
+        0x3000 => Instruction::SeVxByte(x, kk),
+        0x4000 => Instruction::SneVxByte(x, kk),
+        0x5000 => Instruction::SeVxVy(x, y),
+        0x6000 => Instruction::LdVxByte(x, kk),
+        0x7000 => Instruction::AddVxByte(x, kk),
+        0x8000 => match opcode & 0x000F {
+            0x0 => Instruction::LdVxVy(x, y),
+            0x1 => Instruction::OrVxVy(x, y),
+            0x2 => Instruction::AndVxVy(x, y),
+            0x3 => Instruction::XorVxVy(x, y),
+            0x4 => Instruction::AddVxVy(x, y),
+            0x5 => Instruction::SubVxVy(x, y),
+            0x6 => Instruction::ShrVxVy(x, y),
+            0x7 => Instruction::SubnVxVy(x, y),
+            0xE => Instruction::ShlVxVy(x, y),
+            _ => Instruction::Unknown(opcode),
+        },
+        0x9000 => Instruction::SneVxVy(x, y),
+        0xA000 => Instruction::LdI(nnn),
+        0xB000 => Instruction::JpV0(nnn, x),
+        0xC000 => Instruction::Rnd(x, kk),
+        0xD000 => Instruction::Drw(x, y, n),
+        0xE000 => match opcode & 0x00FF {
+            0x9E => Instruction::Skp(x),
+            0xA1 => Instruction::Sknp(x),
+            _ => Instruction::Unknown(opcode),
+        },
+        0xF000 => match opcode & 0x00FF {
+            0x07 => Instruction::LdVxDt(x),
+            0x0A => Instruction::LdVxK(x),
+            0x15 => Instruction::LdDtVx(x),
+            0x18 => Instruction::LdStVx(x),
+            0x1E => Instruction::AddIVx(x),
+            0x29 => Instruction::LdFVx(x),
+            0x33 => Instruction::LdBVx(x),
+            0x55 => Instruction::LdIVx(x),
+            0x65 => Instruction::LdVxI(x),
+            _ => Instruction::Unknown(opcode),
+        },
+        _ => Instruction::Unknown(opcode),
+    }
+}